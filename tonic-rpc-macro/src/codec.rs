@@ -0,0 +1,49 @@
+//! Internal marker types used to pick which codec a generated `MyMethod`/
+//! `MyService` reports as its `CODEC_PATH`.
+//!
+//! These mirror the codec modules and feature flags exposed by the
+//! `tonic-rpc` crate, but are kept self-contained here (rather than
+//! depending on `tonic-rpc`) to avoid a dependency cycle between the two
+//! crates.
+
+/// Selects the `CODEC_PATH` a generated `MyMethod`/`MyService` reports.
+pub trait CodecMarker {
+    /// Path to the codec type, e.g. `"tonic_rpc::json_codec::MyCodec"`.
+    const PATH: &'static str;
+}
+
+/// The `json` codec, backed by `serde_json`.
+#[cfg(feature = "json")]
+pub struct Json;
+
+#[cfg(feature = "json")]
+impl CodecMarker for Json {
+    const PATH: &'static str = "tonic_rpc::json_codec::MyCodec";
+}
+
+/// The `bincode` codec, backed by `bincode`.
+#[cfg(feature = "bincode")]
+pub struct Bincode;
+
+#[cfg(feature = "bincode")]
+impl CodecMarker for Bincode {
+    const PATH: &'static str = "tonic_rpc::bincode_codec::MyCodec";
+}
+
+/// The `cbor` codec, backed by `serde_cbor`.
+#[cfg(feature = "cbor")]
+pub struct Cbor;
+
+#[cfg(feature = "cbor")]
+impl CodecMarker for Cbor {
+    const PATH: &'static str = "tonic_rpc::cbor_codec::MyCodec";
+}
+
+/// The `messagepack` codec, backed by `rmp-serde`.
+#[cfg(feature = "messagepack")]
+pub struct MessagePack;
+
+#[cfg(feature = "messagepack")]
+impl CodecMarker for MessagePack {
+    const PATH: &'static str = "tonic_rpc::messagepack_codec::MyCodec";
+}
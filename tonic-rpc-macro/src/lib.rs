@@ -1,11 +1,18 @@
+use std::marker::PhantomData;
+
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::{
-    parse_macro_input, punctuated::Pair, FnArg, ItemTrait, ReturnType, TraitItem, TraitItemMethod,
+    parse_macro_input, punctuated::Punctuated, FnArg, ItemTrait, ReturnType, Token, TraitItem,
+    TraitItemMethod,
 };
 use tonic_build::{Method, Service};
 
-struct MyMethod {
+mod codec;
+
+use codec::CodecMarker;
+
+struct MyMethod<C> {
     pub name: String,
     pub identifier: String,
     pub client_streaming: bool,
@@ -14,10 +21,12 @@ struct MyMethod {
     pub response: proc_macro2::TokenStream,
     pub generated_request: syn::Ident,
     pub generated_response: syn::Ident,
+    pub comment: Vec<String>,
+    pub codec: PhantomData<C>,
 }
 
-impl Method for MyMethod {
-    const CODEC_PATH: &'static str = "tonic_rpc::json_codec::MyCodec";
+impl<C: CodecMarker> Method for MyMethod<C> {
+    const CODEC_PATH: &'static str = C::PATH;
     type Comment = String;
 
     fn name(&self) -> &str {
@@ -27,7 +36,7 @@ impl Method for MyMethod {
         &self.identifier
     }
     fn comment(&self) -> &[Self::Comment] {
-        &[]
+        &self.comment
     }
     fn client_streaming(&self) -> bool {
         self.client_streaming
@@ -45,17 +54,18 @@ impl Method for MyMethod {
     }
 }
 
-struct MyService {
+struct MyService<C> {
     pub name: String,
     pub package: String,
     pub identifier: String,
-    pub methods: Vec<MyMethod>,
+    pub methods: Vec<MyMethod<C>>,
+    pub comment: Vec<String>,
 }
 
-impl Service for MyService {
-    const CODEC_PATH: &'static str = "tonic_rpc::json_codec::MyCodec";
+impl<C: CodecMarker> Service for MyService<C> {
+    const CODEC_PATH: &'static str = C::PATH;
     type Comment = String;
-    type Method = MyMethod;
+    type Method = MyMethod<C>;
 
     fn name(&self) -> &str {
         &self.name
@@ -67,26 +77,57 @@ impl Service for MyService {
         &self.identifier
     }
     fn comment(&self) -> &[Self::Comment] {
-        &[]
+        &self.comment
     }
     fn methods(&self) -> &[Self::Method] {
         &self.methods
     }
 }
 
-fn make_method(method: TraitItemMethod, trait_name: &str) -> MyMethod {
+/// Collects the text of each `#[doc = "..."]` attribute (i.e. each `///`
+/// line) attached to an item, in source order.
+fn doc_comments(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter_map(|attr| {
+            if !attr.path.is_ident("doc") {
+                return None;
+            }
+            match attr.parse_meta() {
+                Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                    lit: syn::Lit::Str(doc),
+                    ..
+                })) => Some(doc.value()),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn make_method<C>(method: TraitItemMethod, trait_name: &str) -> MyMethod<C> {
+    let comment = doc_comments(&method.attrs);
     let name = method.sig.ident.to_string();
     let server_streaming = method
         .attrs
         .iter()
         .any(|attr| attr.path.is_ident("server_streaming"));
-    let mut args: Vec<_> = method.sig.inputs.into_pairs().collect();
-    if args.len() != 1 {
-        panic!("Invalid rpc argument type");
-    }
-    let request = match args.pop() {
-        Some(Pair::End(FnArg::Typed(pat))) => pat.ty.to_token_stream(),
-        _ => panic!("Invalid rpc argument type"),
+    let client_streaming = method
+        .attrs
+        .iter()
+        .any(|attr| attr.path.is_ident("client_streaming"));
+    let arg_types: Vec<_> = method
+        .sig
+        .inputs
+        .into_iter()
+        .map(|arg| match arg {
+            FnArg::Typed(pat) => pat.ty.to_token_stream(),
+            FnArg::Receiver(_) => panic!("Invalid rpc argument type"),
+        })
+        .collect();
+    let request = match arg_types.as_slice() {
+        [] => quote! { () },
+        [ty] => quote! { #ty },
+        types => quote! { ( #(#types),* ) },
     };
     let response = match method.sig.output {
         ReturnType::Default => quote! { "()" },
@@ -95,7 +136,7 @@ fn make_method(method: TraitItemMethod, trait_name: &str) -> MyMethod {
     MyMethod {
         identifier: name.clone(),
         name: name.clone(),
-        client_streaming: false,
+        client_streaming,
         server_streaming,
         request,
         response,
@@ -109,24 +150,116 @@ fn make_method(method: TraitItemMethod, trait_name: &str) -> MyMethod {
             trait_name,
             name.clone()
         ),
+        comment,
+        codec: PhantomData,
     }
 }
 
-#[proc_macro_attribute]
-pub fn tonic_rpc(_attributes: TokenStream, item: TokenStream) -> TokenStream {
-    let trait_ = parse_macro_input!(item as ItemTrait);
+/// Converts a `PascalCase` identifier into the `snake_case` form that
+/// `tonic_build` uses as the prefix of the generated server module, e.g.
+/// `MyService` becomes `my_service` (the module being `my_service_server`).
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, ch) in name.chars().enumerate() {
+        if ch.is_uppercase() {
+            if i != 0 {
+                out.push('_');
+            }
+            out.extend(ch.to_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+/// Converts a `snake_case` method name into the `PascalCase` form
+/// `tonic_build` uses for a server-streaming method's associated stream
+/// type, e.g. `list_items` becomes `ListItems`.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Generates a zero-field struct implementing the generated server trait
+/// with every method returning `Err(tonic::Status::unimplemented(..))`,
+/// for the `default_stubs` attribute.
+fn generate_default_stubs<C: CodecMarker>(service: &MyService<C>) -> proc_macro2::TokenStream {
+    let server_mod = format_ident!("{}_server", snake_case(&service.name));
+    let trait_ident = format_ident!("{}", service.name);
+    let stub_ident = format_ident!("{}DefaultStubs", service.name);
+    let stream_assoc_types = service.methods.iter().filter(|m| m.server_streaming).map(|m| {
+        let stream_ident = format_ident!("{}Stream", pascal_case(&m.name));
+        let response = m.generated_response.clone();
+        quote! {
+            type #stream_ident = std::pin::Pin<Box<
+                dyn tonic::codegen::futures_core::Stream<Item = Result<#response, tonic::Status>> + Send + 'static,
+            >>;
+        }
+    });
+    let method_impls = service.methods.iter().map(|m| {
+        let method_ident = format_ident!("{}", m.name);
+        let request = m.generated_request.clone();
+        let request_ty = if m.client_streaming {
+            quote! { tonic::Streaming<#request> }
+        } else {
+            quote! { #request }
+        };
+        let response_ty = if m.server_streaming {
+            let stream_ident = format_ident!("{}Stream", pascal_case(&m.name));
+            quote! { Self::#stream_ident }
+        } else {
+            let response = m.generated_response.clone();
+            quote! { #response }
+        };
+        let message = format!("{} is not implemented", m.name);
+        quote! {
+            async fn #method_ident(
+                &self,
+                _request: tonic::Request<#request_ty>,
+            ) -> Result<tonic::Response<#response_ty>, tonic::Status> {
+                Err(tonic::Status::unimplemented(#message))
+            }
+        }
+    });
+    quote! {
+        /// A default implementation of the generated server trait whose
+        /// methods all return `Err(tonic::Status::unimplemented(..))`.
+        ///
+        /// Generated because of the `default_stubs` attribute, so that a
+        /// service can be stood up and filled in one method at a time.
+        #[derive(Debug, Default)]
+        pub struct #stub_ident;
+
+        #[tonic::async_trait]
+        impl #server_mod::#trait_ident for #stub_ident {
+            #( #stream_assoc_types )*
+            #( #method_impls )*
+        }
+    }
+}
+
+fn generate<C: CodecMarker>(trait_: ItemTrait, default_stubs: bool) -> TokenStream {
     let name = trait_.ident.to_string();
     let methods: Vec<_> = trait_
         .items
         .into_iter()
         .filter_map(|item| match item {
-            TraitItem::Method(method) => Some(make_method(method, &name)),
+            TraitItem::Method(method) => Some(make_method::<C>(method, &name)),
             _ => None,
         })
         .collect();
     let service = MyService {
         package: name.clone(),
         identifier: name.clone(),
+        comment: doc_comments(&trait_.attrs),
         name,
         methods,
     };
@@ -143,10 +276,114 @@ pub fn tonic_rpc(_attributes: TokenStream, item: TokenStream) -> TokenStream {
         }
     });
     let types = quote! { #( #types )*};
+    let default_stubs = if default_stubs {
+        generate_default_stubs(&service)
+    } else {
+        quote! {}
+    };
     (quote! {
         #types
         #client
         #server
+        #default_stubs
     })
     .into()
 }
+
+#[proc_macro_attribute]
+pub fn tonic_rpc(attributes: TokenStream, item: TokenStream) -> TokenStream {
+    let trait_ = parse_macro_input!(item as ItemTrait);
+    let attrs =
+        parse_macro_input!(attributes with Punctuated::<syn::Ident, Token![,]>::parse_terminated);
+    let mut codec = None;
+    let mut default_stubs = false;
+    for ident in attrs {
+        match ident.to_string().as_str() {
+            "default_stubs" => default_stubs = true,
+            other => codec = Some(other.to_string()),
+        }
+    }
+    let codec = codec.unwrap_or_else(|| "json".to_string());
+    match codec.as_str() {
+        #[cfg(feature = "json")]
+        "json" => generate::<codec::Json>(trait_, default_stubs),
+        #[cfg(not(feature = "json"))]
+        "json" => quote! {
+            compile_error!("codec `json` selected but the `json` feature is not enabled");
+        }
+        .into(),
+
+        #[cfg(feature = "bincode")]
+        "bincode" => generate::<codec::Bincode>(trait_, default_stubs),
+        #[cfg(not(feature = "bincode"))]
+        "bincode" => quote! {
+            compile_error!("codec `bincode` selected but the `bincode` feature is not enabled");
+        }
+        .into(),
+
+        #[cfg(feature = "cbor")]
+        "cbor" => generate::<codec::Cbor>(trait_, default_stubs),
+        #[cfg(not(feature = "cbor"))]
+        "cbor" => quote! {
+            compile_error!("codec `cbor` selected but the `cbor` feature is not enabled");
+        }
+        .into(),
+
+        #[cfg(feature = "messagepack")]
+        "messagepack" => generate::<codec::MessagePack>(trait_, default_stubs),
+        #[cfg(not(feature = "messagepack"))]
+        "messagepack" => quote! {
+            compile_error!(
+                "codec `messagepack` selected but the `messagepack` feature is not enabled"
+            );
+        }
+        .into(),
+
+        other => panic!(
+            "Unrecognized codec `{}`; expected one of `json`, `bincode`, `cbor`, `messagepack`",
+            other
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::{doc_comments, TraitItem};
+
+    #[test]
+    fn doc_comments_collects_trait_and_method_docs_in_order() {
+        let item: syn::ItemTrait = parse_quote! {
+            /// Line one.
+            /// Line two.
+            trait Foo {
+                /// Does a thing.
+                fn bar(x: i32) -> i32;
+            }
+        };
+        assert_eq!(
+            vec![" Line one.".to_string(), " Line two.".to_string()],
+            doc_comments(&item.attrs),
+        );
+
+        let method = match item.items.into_iter().next() {
+            Some(TraitItem::Method(method)) => method,
+            _ => panic!("expected a method"),
+        };
+        assert_eq!(
+            vec![" Does a thing.".to_string()],
+            doc_comments(&method.attrs),
+        );
+    }
+
+    #[test]
+    fn doc_comments_is_empty_without_doc_attributes() {
+        let item: syn::ItemTrait = parse_quote! {
+            trait Foo {
+                fn bar(x: i32) -> i32;
+            }
+        };
+        assert!(doc_comments(&item.attrs).is_empty());
+    }
+}
@@ -0,0 +1,305 @@
+//! Serialization codecs used by the generated `gRPC` services.
+//!
+//! Exactly one of these modules is selected per service via the
+//! `#[tonic_rpc(..)]` attribute described in the [crate-level docs](crate);
+//! each is gated behind the feature flag of the same name.
+
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+pub mod json_codec {
+    //! A [`tonic::codec::Codec`] that serializes requests/responses as JSON
+    //! using [`serde_json`].
+
+    use std::marker::PhantomData;
+
+    use bytes::{Buf, BufMut};
+    use serde::{de::DeserializeOwned, Serialize};
+    use tonic::{
+        codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder},
+        Status,
+    };
+
+    /// A [`Codec`] that serializes requests/responses as JSON.
+    #[derive(Debug)]
+    pub struct MyCodec<T, U>(PhantomData<(T, U)>);
+
+    impl<T, U> Default for MyCodec<T, U> {
+        fn default() -> Self {
+            MyCodec(PhantomData)
+        }
+    }
+
+    impl<T, U> Codec for MyCodec<T, U>
+    where
+        T: Serialize + Send + 'static,
+        U: DeserializeOwned + Send + 'static,
+    {
+        type Encode = T;
+        type Decode = U;
+        type Encoder = MyEncoder<T>;
+        type Decoder = MyDecoder<U>;
+
+        fn encoder(&mut self) -> Self::Encoder {
+            MyEncoder(PhantomData)
+        }
+
+        fn decoder(&mut self) -> Self::Decoder {
+            MyDecoder(PhantomData)
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct MyEncoder<T>(PhantomData<T>);
+
+    impl<T: Serialize> Encoder for MyEncoder<T> {
+        type Item = T;
+        type Error = Status;
+
+        fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+            serde_json::to_writer(dst.writer(), &item)
+                .map_err(|err| Status::internal(format!("Error serializing response: {}", err)))
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct MyDecoder<U>(PhantomData<U>);
+
+    impl<U: DeserializeOwned> Decoder for MyDecoder<U> {
+        type Item = U;
+        type Error = Status;
+
+        fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+            if !src.has_remaining() {
+                return Ok(None);
+            }
+            let item = serde_json::from_reader(src.reader())
+                .map_err(|err| Status::internal(format!("Error deserializing request: {}", err)))?;
+            Ok(Some(item))
+        }
+    }
+}
+
+#[cfg(feature = "bincode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+pub mod bincode_codec {
+    //! A [`tonic::codec::Codec`] that serializes requests/responses using
+    //! [`bincode`].
+
+    use std::marker::PhantomData;
+
+    use bytes::{Buf, BufMut};
+    use serde::{de::DeserializeOwned, Serialize};
+    use tonic::{
+        codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder},
+        Status,
+    };
+
+    /// A [`Codec`] that serializes requests/responses using `bincode`.
+    #[derive(Debug)]
+    pub struct MyCodec<T, U>(PhantomData<(T, U)>);
+
+    impl<T, U> Default for MyCodec<T, U> {
+        fn default() -> Self {
+            MyCodec(PhantomData)
+        }
+    }
+
+    impl<T, U> Codec for MyCodec<T, U>
+    where
+        T: Serialize + Send + 'static,
+        U: DeserializeOwned + Send + 'static,
+    {
+        type Encode = T;
+        type Decode = U;
+        type Encoder = MyEncoder<T>;
+        type Decoder = MyDecoder<U>;
+
+        fn encoder(&mut self) -> Self::Encoder {
+            MyEncoder(PhantomData)
+        }
+
+        fn decoder(&mut self) -> Self::Decoder {
+            MyDecoder(PhantomData)
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct MyEncoder<T>(PhantomData<T>);
+
+    impl<T: Serialize> Encoder for MyEncoder<T> {
+        type Item = T;
+        type Error = Status;
+
+        fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+            bincode::serialize_into(dst.writer(), &item)
+                .map_err(|err| Status::internal(format!("Error serializing response: {}", err)))
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct MyDecoder<U>(PhantomData<U>);
+
+    impl<U: DeserializeOwned> Decoder for MyDecoder<U> {
+        type Item = U;
+        type Error = Status;
+
+        fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+            if !src.has_remaining() {
+                return Ok(None);
+            }
+            let item = bincode::deserialize_from(src.reader())
+                .map_err(|err| Status::internal(format!("Error deserializing request: {}", err)))?;
+            Ok(Some(item))
+        }
+    }
+}
+
+#[cfg(feature = "cbor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cbor")))]
+pub mod cbor_codec {
+    //! A [`tonic::codec::Codec`] that serializes requests/responses using
+    //! [`serde_cbor`].
+
+    use std::marker::PhantomData;
+
+    use bytes::{Buf, BufMut};
+    use serde::{de::DeserializeOwned, Serialize};
+    use tonic::{
+        codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder},
+        Status,
+    };
+
+    /// A [`Codec`] that serializes requests/responses using `serde_cbor`.
+    #[derive(Debug)]
+    pub struct MyCodec<T, U>(PhantomData<(T, U)>);
+
+    impl<T, U> Default for MyCodec<T, U> {
+        fn default() -> Self {
+            MyCodec(PhantomData)
+        }
+    }
+
+    impl<T, U> Codec for MyCodec<T, U>
+    where
+        T: Serialize + Send + 'static,
+        U: DeserializeOwned + Send + 'static,
+    {
+        type Encode = T;
+        type Decode = U;
+        type Encoder = MyEncoder<T>;
+        type Decoder = MyDecoder<U>;
+
+        fn encoder(&mut self) -> Self::Encoder {
+            MyEncoder(PhantomData)
+        }
+
+        fn decoder(&mut self) -> Self::Decoder {
+            MyDecoder(PhantomData)
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct MyEncoder<T>(PhantomData<T>);
+
+    impl<T: Serialize> Encoder for MyEncoder<T> {
+        type Item = T;
+        type Error = Status;
+
+        fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+            serde_cbor::to_writer(dst.writer(), &item)
+                .map_err(|err| Status::internal(format!("Error serializing response: {}", err)))
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct MyDecoder<U>(PhantomData<U>);
+
+    impl<U: DeserializeOwned> Decoder for MyDecoder<U> {
+        type Item = U;
+        type Error = Status;
+
+        fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+            if !src.has_remaining() {
+                return Ok(None);
+            }
+            let item = serde_cbor::from_reader(src.reader())
+                .map_err(|err| Status::internal(format!("Error deserializing request: {}", err)))?;
+            Ok(Some(item))
+        }
+    }
+}
+
+#[cfg(feature = "messagepack")]
+#[cfg_attr(docsrs, doc(cfg(feature = "messagepack")))]
+pub mod messagepack_codec {
+    //! A [`tonic::codec::Codec`] that serializes requests/responses using
+    //! [`rmp_serde`].
+
+    use std::marker::PhantomData;
+
+    use bytes::{Buf, BufMut};
+    use serde::{de::DeserializeOwned, Serialize};
+    use tonic::{
+        codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder},
+        Status,
+    };
+
+    /// A [`Codec`] that serializes requests/responses using `rmp-serde`.
+    #[derive(Debug)]
+    pub struct MyCodec<T, U>(PhantomData<(T, U)>);
+
+    impl<T, U> Default for MyCodec<T, U> {
+        fn default() -> Self {
+            MyCodec(PhantomData)
+        }
+    }
+
+    impl<T, U> Codec for MyCodec<T, U>
+    where
+        T: Serialize + Send + 'static,
+        U: DeserializeOwned + Send + 'static,
+    {
+        type Encode = T;
+        type Decode = U;
+        type Encoder = MyEncoder<T>;
+        type Decoder = MyDecoder<U>;
+
+        fn encoder(&mut self) -> Self::Encoder {
+            MyEncoder(PhantomData)
+        }
+
+        fn decoder(&mut self) -> Self::Decoder {
+            MyDecoder(PhantomData)
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct MyEncoder<T>(PhantomData<T>);
+
+    impl<T: Serialize> Encoder for MyEncoder<T> {
+        type Item = T;
+        type Error = Status;
+
+        fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+            rmp_serde::encode::write(&mut dst.writer(), &item)
+                .map_err(|err| Status::internal(format!("Error serializing response: {}", err)))
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct MyDecoder<U>(PhantomData<U>);
+
+    impl<U: DeserializeOwned> Decoder for MyDecoder<U> {
+        type Item = U;
+        type Error = Status;
+
+        fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+            if !src.has_remaining() {
+                return Ok(None);
+            }
+            let item = rmp_serde::decode::from_read(src.reader())
+                .map_err(|err| Status::internal(format!("Error deserializing request: {}", err)))?;
+            Ok(Some(item))
+        }
+    }
+}
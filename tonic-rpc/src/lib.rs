@@ -125,6 +125,13 @@
 //!
 //! Examples that use streaming can be found in the [tests folder](https://github.com/adamrk/tonic-rpc/tree/main/tonic-rpc/tests).
 //!
+//! # Default stubs
+//! Adding `default_stubs` to the attribute, e.g. `#[tonic_rpc(json, default_stubs)]`,
+//! additionally generates a `{Trait}DefaultStubs` struct implementing the server trait
+//! with every method returning `Err(tonic::Status::unimplemented(..))`. This gives you
+//! a compiling server to start `tonic::transport::Server` with immediately, and you can
+//! replace `{Trait}DefaultStubs` with your own type as you implement each method.
+//!
 //! # Request/Response types
 //!
 //! The traits and functions generated by [`tonic-rpc`] will be transformations
@@ -173,6 +180,11 @@
 //! async fn f(..) -> Result::<tonic::Response<Self::FStream>, tonic::Status>
 //! ```
 //!
+//! # Doc comments
+//! Doc comments on the service trait and its methods are propagated to the generated
+//! client and server, so `cargo doc` and IDE hovers on the generated types show the
+//! same documentation as the trait they were defined from.
+//!
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
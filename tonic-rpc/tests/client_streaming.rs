@@ -0,0 +1,51 @@
+//! Exercises `#[client_streaming]`-only methods end-to-end against a real
+//! in-process server/client, the same way the crate-level doctest does.
+
+#![cfg(feature = "json")]
+
+use tokio_stream::wrappers::TcpListenerStream;
+
+#[tonic_rpc::tonic_rpc(json)]
+trait ClientStreamingCounter {
+    #[client_streaming]
+    fn sum(x: i32) -> i32;
+}
+
+struct State;
+
+#[tonic::async_trait]
+impl client_streaming_counter_server::ClientStreamingCounter for State {
+    async fn sum(
+        &self,
+        request: tonic::Request<tonic::Streaming<i32>>,
+    ) -> Result<tonic::Response<i32>, tonic::Status> {
+        let mut inbound = request.into_inner();
+        let mut total = 0;
+        while let Some(x) = inbound.message().await? {
+            total += x;
+        }
+        Ok(tonic::Response::new(total))
+    }
+}
+
+#[tokio::test]
+async fn client_streaming_sums_requests() {
+    let listener = tokio::net::TcpListener::bind("[::1]:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        tonic::transport::Server::builder()
+            .add_service(client_streaming_counter_server::ClientStreamingCounterServer::new(State))
+            .serve_with_incoming(TcpListenerStream::new(listener))
+            .await
+    });
+
+    let mut client = client_streaming_counter_client::ClientStreamingCounterClient::connect(
+        format!("http://{}", addr),
+    )
+    .await
+    .unwrap();
+
+    let outbound = tokio_stream::iter(vec![1, 2, 3, 4]);
+    let response = client.sum(outbound).await.unwrap().into_inner();
+    assert_eq!(10, response);
+}
@@ -0,0 +1,125 @@
+//! Exercises `#[tonic_rpc(..)]` codec selection for each non-default
+//! encoding, round-tripping a request/response against a real in-process
+//! server/client.
+
+#[cfg(feature = "bincode")]
+mod bincode_codec {
+    use tokio_stream::wrappers::TcpListenerStream;
+
+    #[tonic_rpc::tonic_rpc(bincode)]
+    trait BincodeEcho {
+        fn echo(x: i32) -> i32;
+    }
+
+    struct State;
+
+    #[tonic::async_trait]
+    impl bincode_echo_server::BincodeEcho for State {
+        async fn echo(
+            &self,
+            request: tonic::Request<i32>,
+        ) -> Result<tonic::Response<i32>, tonic::Status> {
+            Ok(tonic::Response::new(request.into_inner()))
+        }
+    }
+
+    #[tokio::test]
+    async fn bincode_codec_round_trips() {
+        let listener = tokio::net::TcpListener::bind("[::1]:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            tonic::transport::Server::builder()
+                .add_service(bincode_echo_server::BincodeEchoServer::new(State))
+                .serve_with_incoming(TcpListenerStream::new(listener))
+                .await
+        });
+
+        let mut client =
+            bincode_echo_client::BincodeEchoClient::connect(format!("http://{}", addr))
+                .await
+                .unwrap();
+        let response = client.echo(42).await.unwrap().into_inner();
+        assert_eq!(42, response);
+    }
+}
+
+#[cfg(feature = "cbor")]
+mod cbor_codec {
+    use tokio_stream::wrappers::TcpListenerStream;
+
+    #[tonic_rpc::tonic_rpc(cbor)]
+    trait CborEcho {
+        fn echo(x: i32) -> i32;
+    }
+
+    struct State;
+
+    #[tonic::async_trait]
+    impl cbor_echo_server::CborEcho for State {
+        async fn echo(
+            &self,
+            request: tonic::Request<i32>,
+        ) -> Result<tonic::Response<i32>, tonic::Status> {
+            Ok(tonic::Response::new(request.into_inner()))
+        }
+    }
+
+    #[tokio::test]
+    async fn cbor_codec_round_trips() {
+        let listener = tokio::net::TcpListener::bind("[::1]:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            tonic::transport::Server::builder()
+                .add_service(cbor_echo_server::CborEchoServer::new(State))
+                .serve_with_incoming(TcpListenerStream::new(listener))
+                .await
+        });
+
+        let mut client = cbor_echo_client::CborEchoClient::connect(format!("http://{}", addr))
+            .await
+            .unwrap();
+        let response = client.echo(42).await.unwrap().into_inner();
+        assert_eq!(42, response);
+    }
+}
+
+#[cfg(feature = "messagepack")]
+mod messagepack_codec {
+    use tokio_stream::wrappers::TcpListenerStream;
+
+    #[tonic_rpc::tonic_rpc(messagepack)]
+    trait MessagePackEcho {
+        fn echo(x: i32) -> i32;
+    }
+
+    struct State;
+
+    #[tonic::async_trait]
+    impl message_pack_echo_server::MessagePackEcho for State {
+        async fn echo(
+            &self,
+            request: tonic::Request<i32>,
+        ) -> Result<tonic::Response<i32>, tonic::Status> {
+            Ok(tonic::Response::new(request.into_inner()))
+        }
+    }
+
+    #[tokio::test]
+    async fn messagepack_codec_round_trips() {
+        let listener = tokio::net::TcpListener::bind("[::1]:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            tonic::transport::Server::builder()
+                .add_service(message_pack_echo_server::MessagePackEchoServer::new(State))
+                .serve_with_incoming(TcpListenerStream::new(listener))
+                .await
+        });
+
+        let mut client =
+            message_pack_echo_client::MessagePackEchoClient::connect(format!("http://{}", addr))
+                .await
+                .unwrap();
+        let response = client.echo(42).await.unwrap().into_inner();
+        assert_eq!(42, response);
+    }
+}
@@ -0,0 +1,20 @@
+//! Exercises `default_stubs` on a service with a server-streaming method,
+//! which requires the generated stub to also define the method's
+//! associated `...Stream` type.
+
+#![cfg(feature = "json")]
+
+#[tonic_rpc::tonic_rpc(json, default_stubs)]
+trait Counter {
+    #[server_streaming]
+    fn count_to(n: i32) -> i32;
+}
+
+#[tokio::test]
+async fn default_stub_returns_unimplemented_for_streaming_method() {
+    use counter_server::Counter;
+
+    let stub = CounterDefaultStubs::default();
+    let status = stub.count_to(tonic::Request::new(5)).await.unwrap_err();
+    assert_eq!(tonic::Code::Unimplemented, status.code());
+}
@@ -0,0 +1,47 @@
+//! Shows that a service trait whose trait and methods carry doc comments
+//! (see the crate-level "Doc comments" section) still expands into a
+//! working client/server. The generated docs themselves are not inspected.
+
+#![cfg(feature = "json")]
+
+use tokio_stream::wrappers::TcpListenerStream;
+
+/// A documented increment service.
+#[tonic_rpc::tonic_rpc(json)]
+trait DocumentedIncrement {
+    /// Increments the given value by one.
+    fn increment(arg: i32) -> i32;
+}
+
+struct State;
+
+#[tonic::async_trait]
+impl documented_increment_server::DocumentedIncrement for State {
+    async fn increment(
+        &self,
+        request: tonic::Request<i32>,
+    ) -> Result<tonic::Response<i32>, tonic::Status> {
+        Ok(tonic::Response::new(request.into_inner() + 1))
+    }
+}
+
+#[tokio::test]
+async fn documented_service_compiles_and_round_trips() {
+    let listener = tokio::net::TcpListener::bind("[::1]:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        tonic::transport::Server::builder()
+            .add_service(documented_increment_server::DocumentedIncrementServer::new(
+                State,
+            ))
+            .serve_with_incoming(TcpListenerStream::new(listener))
+            .await
+    });
+
+    let mut client =
+        documented_increment_client::DocumentedIncrementClient::connect(format!("http://{}", addr))
+            .await
+            .unwrap();
+    let response = client.increment(32).await.unwrap().into_inner();
+    assert_eq!(33, response);
+}
@@ -0,0 +1,42 @@
+//! Exercises a multi-argument method, whose arguments get bundled into a
+//! tuple request, end-to-end against a real in-process server/client.
+
+#![cfg(feature = "json")]
+
+use tokio_stream::wrappers::TcpListenerStream;
+
+#[tonic_rpc::tonic_rpc(json)]
+trait Adder {
+    fn add(x: i32, y: i32) -> i32;
+}
+
+struct State;
+
+#[tonic::async_trait]
+impl adder_server::Adder for State {
+    async fn add(
+        &self,
+        request: tonic::Request<(i32, i32)>,
+    ) -> Result<tonic::Response<i32>, tonic::Status> {
+        let (x, y) = request.into_inner();
+        Ok(tonic::Response::new(x + y))
+    }
+}
+
+#[tokio::test]
+async fn multi_argument_method_bundles_into_tuple() {
+    let listener = tokio::net::TcpListener::bind("[::1]:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        tonic::transport::Server::builder()
+            .add_service(adder_server::AdderServer::new(State))
+            .serve_with_incoming(TcpListenerStream::new(listener))
+            .await
+    });
+
+    let mut client = adder_client::AdderClient::connect(format!("http://{}", addr))
+        .await
+        .unwrap();
+    let response = client.add((3, 4)).await.unwrap().into_inner();
+    assert_eq!(7, response);
+}